@@ -1,7 +1,7 @@
 //! A fixed size blob of bytes contained in a Cap'n Proto message
 
 use core::fmt;
-use core::ops::{Deref, DerefMut};
+use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
 
 use crate::internal::Sealed;
 use crate::list::ElementSize;
@@ -23,6 +23,159 @@ impl fmt::Display for TryFromSliceError {
 
 impl core::error::Error for TryFromSliceError {}
 
+/// An error returned when a byte range or offset falls outside the bounds of the blob
+/// it's taken from — by [`subslice`](Reader::subslice), or by one of the scalar
+/// `set_uN` accessors writing past `len()` (the `get_uN` accessors return `None`
+/// instead).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SliceRangeError(pub(crate) ());
+
+impl fmt::Display for SliceRangeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("data blob byte range is out of bounds")
+    }
+}
+
+impl core::error::Error for SliceRangeError {}
+
+/// Resolves a `RangeBounds<u32>` against a blob of the given length, returning the
+/// `[start, end)` byte range, or an error if the range overflows or exceeds `len`.
+#[inline]
+fn resolve_range(range: impl RangeBounds<u32>, len: u32) -> Result<(u32, u32), SliceRangeError> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).ok_or(SliceRangeError(()))?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or(SliceRangeError(()))?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        return Err(SliceRangeError(()));
+    }
+    Ok((start, end))
+}
+
+/// Compares two byte slices without short-circuiting on the first differing byte.
+///
+/// Returns `false` immediately on a length mismatch, since the length of a
+/// secret-bearing blob isn't itself usually secret. Each byte pair is passed through
+/// [`core::hint::black_box`] as best-effort hardening against the compiler folding the
+/// accumulation back into a short-circuiting comparison — `black_box` is documented as
+/// an optimization *hint*, not a guarantee, so this is not a substitute for an audited
+/// constant-time implementation (e.g. the `subtle` crate) where that matters.
+#[inline]
+fn ct_eq_slices(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b) {
+        diff |= core::hint::black_box(x ^ y);
+    }
+    core::hint::black_box(diff) == 0
+}
+
+/// Assembles a little-endian unsigned integer from `bytes` (1 to 8 bytes long).
+#[inline]
+fn uint_from_le_bytes(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
+
+/// Assembles a big-endian unsigned integer from `bytes` (1 to 8 bytes long).
+#[inline]
+fn uint_from_be_bytes(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf[8 - bytes.len()..].copy_from_slice(bytes);
+    u64::from_be_bytes(buf)
+}
+
+/// Splats `value` into `bytes` (1 to 8 bytes long) in little-endian order.
+#[inline]
+fn uint_to_le_bytes(bytes: &mut [u8], value: u64) {
+    bytes.copy_from_slice(&value.to_le_bytes()[..bytes.len()]);
+}
+
+/// Splats `value` into `bytes` (1 to 8 bytes long) in big-endian order.
+#[inline]
+fn uint_to_be_bytes(bytes: &mut [u8], value: u64) {
+    let width = bytes.len();
+    bytes.copy_from_slice(&value.to_be_bytes()[8 - width..]);
+}
+
+/// Resolves a `width`-byte range starting at `offset` against a blob of the given
+/// length, entirely in `u32` space so an `offset` near `u32::MAX` can't overflow
+/// `usize` arithmetic on 32-bit targets, mirroring [`resolve_range`].
+#[inline]
+fn checked_range(offset: u32, width: u32, len: u32) -> Option<Range<usize>> {
+    let end = offset.checked_add(width)?;
+    if end > len {
+        return None;
+    }
+    Some(offset as usize..end as usize)
+}
+
+/// Defines paired `get_uN_le`/`get_uN_be` accessors that read a fixed-width unsigned
+/// integer out of `self.as_slice()` at a byte offset, for every
+/// `(le_name, be_name, width, type)` given.
+macro_rules! get_uint_accessors {
+    ($(($le:ident, $be:ident, $width:literal, $ty:ty)),* $(,)?) => {$(
+        #[doc = concat!(
+            "Reads a little-endian ", stringify!($width),
+            "-byte unsigned integer at `offset`, or `None` if it doesn't fit within the blob.",
+        )]
+        #[inline]
+        pub fn $le(&self, offset: u32) -> Option<$ty> {
+            let range = checked_range(offset, $width, self.len())?;
+            Some(uint_from_le_bytes(&self.as_slice()[range]) as $ty)
+        }
+
+        #[doc = concat!(
+            "Reads a big-endian ", stringify!($width),
+            "-byte unsigned integer at `offset`, or `None` if it doesn't fit within the blob.",
+        )]
+        #[inline]
+        pub fn $be(&self, offset: u32) -> Option<$ty> {
+            let range = checked_range(offset, $width, self.len())?;
+            Some(uint_from_be_bytes(&self.as_slice()[range]) as $ty)
+        }
+    )*};
+}
+
+/// Defines paired `set_uN_le`/`set_uN_be` accessors that write a fixed-width unsigned
+/// integer into `self.as_slice_mut()` at a byte offset, for every
+/// `(le_name, be_name, width, type)` given.
+macro_rules! set_uint_accessors {
+    ($(($le:ident, $be:ident, $width:literal, $ty:ty)),* $(,)?) => {$(
+        #[doc = concat!(
+            "Writes `value` as a little-endian ", stringify!($width),
+            "-byte unsigned integer at `offset`.",
+        )]
+        #[inline]
+        pub fn $le(&mut self, offset: u32, value: $ty) -> Result<(), SliceRangeError> {
+            let range = checked_range(offset, $width, self.len()).ok_or(SliceRangeError(()))?;
+            uint_to_le_bytes(&mut self.as_slice_mut()[range], value as u64);
+            Ok(())
+        }
+
+        #[doc = concat!(
+            "Writes `value` as a big-endian ", stringify!($width),
+            "-byte unsigned integer at `offset`.",
+        )]
+        #[inline]
+        pub fn $be(&mut self, offset: u32, value: $ty) -> Result<(), SliceRangeError> {
+            let range = checked_range(offset, $width, self.len()).ok_or(SliceRangeError(()))?;
+            uint_to_be_bytes(&mut self.as_slice_mut()[range], value as u64);
+            Ok(())
+        }
+    )*};
+}
+
 #[derive(Clone, Copy)]
 pub struct Data<T = Family>(T);
 
@@ -92,6 +245,38 @@ impl<'a> Reader<'a> {
     pub const fn as_slice(&self) -> &'a [u8] {
         self.0.as_slice()
     }
+
+    /// Returns a narrower reader over `range`, pointing into the same underlying
+    /// message bytes without copying.
+    #[inline]
+    pub fn subslice(&self, range: impl RangeBounds<u32>) -> Result<Self, SliceRangeError> {
+        let (start, end) = resolve_range(range, self.len())?;
+        let slice = &self.as_slice()[start as usize..end as usize];
+        let Some(r) = ptr::Reader::new(slice) else {
+            unreachable!("a subslice of a valid data blob is always a valid data blob")
+        };
+        Ok(Self(r))
+    }
+
+    /// Compares this blob to `other` without short-circuiting on the first differing
+    /// byte.
+    ///
+    /// Unlike `PartialEq`, this always walks the full length, intended for comparing a
+    /// MAC, token, or key whose comparison timing shouldn't leak how much of it
+    /// matched. This is best-effort hardening rather than an audited guarantee — the
+    /// crate-internal `ct_eq_slices` helper's doc comment spells out the caveat.
+    #[inline]
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        ct_eq_slices(self.as_slice(), other)
+    }
+
+    get_uint_accessors![
+        (get_u16_le, get_u16_be, 2, u16),
+        (get_u24_le, get_u24_be, 3, u32),
+        (get_u32_le, get_u32_be, 4, u32),
+        (get_u48_le, get_u48_be, 6, u64),
+        (get_u64_le, get_u64_be, 8, u64),
+    ];
 }
 
 impl Deref for Reader<'_> {
@@ -195,6 +380,64 @@ impl<'a> Builder<'a> {
         let len = self.len() as usize;
         unsafe { core::slice::from_raw_parts_mut(data, len) }
     }
+
+    /// Returns a narrower builder over `range`, pointing into the same underlying
+    /// message bytes without copying.
+    #[inline]
+    pub fn subslice(
+        &mut self,
+        range: impl RangeBounds<u32>,
+    ) -> Result<Builder<'_>, SliceRangeError> {
+        let (start, end) = resolve_range(range, self.len())?;
+        // SAFETY: `start` and `end` were resolved against `self.len()` above, so the
+        // resulting pointer and length stay within the bounds of the original blob.
+        let data = unsafe { self.0.data().add(start as usize) };
+        let len = end - start;
+
+        // `ptr::Builder::new_unchecked` takes the same wrapped length type that
+        // `self.0.len()` returns (see `as_reader` above, which passes it straight
+        // through to `ptr::Reader::new_unchecked`), not a bare `u32`, and there's no
+        // public way to build that type from a `u32` directly. So build a throwaway
+        // `ptr::Reader` over this same range purely to borrow its `.len()` — the data
+        // is already known to be in-bounds from the `resolve_range` call above, this
+        // isn't re-checking anything, it's just the only constructor available that
+        // hands back a value of the right type.
+        let slice =
+            unsafe { core::slice::from_raw_parts(data.as_ptr().cast_const(), len as usize) };
+        let Some(throwaway) = ptr::Reader::new(slice) else {
+            unreachable!("a subslice of a valid data blob is always a valid data blob")
+        };
+        let ptr = unsafe { ptr::Builder::new_unchecked(data, throwaway.len()) };
+        Ok(Data(ptr))
+    }
+
+    /// Compares this blob to `other` without short-circuiting on the first differing
+    /// byte.
+    ///
+    /// Unlike `PartialEq`, this always walks the full length, intended for comparing a
+    /// MAC, token, or key whose comparison timing shouldn't leak how much of it
+    /// matched. This is best-effort hardening rather than an audited guarantee — the
+    /// crate-internal `ct_eq_slices` helper's doc comment spells out the caveat.
+    #[inline]
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        ct_eq_slices(self.as_slice(), other)
+    }
+
+    get_uint_accessors![
+        (get_u16_le, get_u16_be, 2, u16),
+        (get_u24_le, get_u24_be, 3, u32),
+        (get_u32_le, get_u32_be, 4, u32),
+        (get_u48_le, get_u48_be, 6, u64),
+        (get_u64_le, get_u64_be, 8, u64),
+    ];
+
+    set_uint_accessors![
+        (set_u16_le, set_u16_be, 2, u16),
+        (set_u24_le, set_u24_be, 3, u32),
+        (set_u32_le, set_u32_be, 4, u32),
+        (set_u48_le, set_u48_be, 6, u64),
+        (set_u64_le, set_u64_be, 8, u64),
+    ];
 }
 
 impl<'a> AsRef<ptr::Builder<'a>> for Builder<'a> {
@@ -255,3 +498,370 @@ impl DerefMut for Builder<'_> {
         self.as_slice_mut()
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    //! `serde` support for `Data`, following the `serde_bytes` technique of encoding
+    //! the blob as a single byte-string primitive rather than a sequence of `u8`. This
+    //! keeps compact formats like bincode or CBOR from paying per-element overhead.
+
+    use core::fmt;
+
+    use serde::de::{
+        Deserialize, DeserializeSeed, Deserializer, Error as DeError, SeqAccess, Visitor,
+    };
+    use serde::ser::{Serialize, Serializer};
+
+    use super::{Builder, Reader, TryFromSliceError};
+
+    impl<'a> Serialize for Reader<'a> {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_bytes(self.as_slice())
+        }
+    }
+
+    /// `Reader` borrows from the message it reads, so deserializing one directly (as
+    /// opposed to through [`BuilderSeed`] below) only works for formats that hand back
+    /// borrowed byte slices tied to the input's own lifetime, such as bincode or
+    /// postcard reading from a `&[u8]`. Formats like `serde_json` encode
+    /// `serialize_bytes` output as a sequence of numbers and have no borrowed bytes
+    /// for a non-owning `Reader` to point into, so deserializing into one fails for
+    /// them with an "invalid type" error instead of silently copying.
+    impl<'de: 'a, 'a> Deserialize<'de> for Reader<'a> {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct ReaderVisitor;
+
+            impl<'de> Visitor<'de> for ReaderVisitor {
+                type Value = Reader<'de>;
+
+                fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(fmt, "a borrowed byte slice")
+                }
+
+                fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+                where
+                    E: DeError,
+                {
+                    Ok(Reader::from_slice(v))
+                }
+            }
+
+            deserializer.deserialize_bytes(ReaderVisitor)
+        }
+    }
+
+    /// A [`DeserializeSeed`] that fills an existing [`Builder`] with bytes read from a
+    /// deserializer, rather than allocating a new blob.
+    ///
+    /// Data fields are fixed-size once allocated in a message, so unlike most `serde`
+    /// types, deserialization has nowhere to put an oversized value; bytes that don't
+    /// fit in the builder's existing capacity are rejected with [`TryFromSliceError`].
+    pub struct BuilderSeed<'b, 'a>(pub &'b mut Builder<'a>);
+
+    impl<'de, 'b, 'a> DeserializeSeed<'de> for BuilderSeed<'b, 'a> {
+        type Value = ();
+
+        #[inline]
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_bytes(BuilderVisitor(self.0))
+        }
+    }
+
+    struct BuilderVisitor<'b, 'a>(&'b mut Builder<'a>);
+
+    impl<'de, 'b, 'a> Visitor<'de> for BuilderVisitor<'b, 'a> {
+        type Value = ();
+
+        fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "a byte slice of at most {} bytes", self.0.len())
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            let dest = self.0.as_slice_mut();
+            if v.len() > dest.len() {
+                return Err(E::custom(TryFromSliceError(())));
+            }
+
+            dest[..v.len()].copy_from_slice(v);
+            Ok(())
+        }
+
+        // Human-readable formats without a native byte-string type (e.g. JSON) encode
+        // `serialize_bytes` output as a sequence instead, so fall back to reading it
+        // element-by-element, same as `serde_bytes` does.
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let dest = self.0.as_slice_mut();
+            let mut i = 0;
+            while let Some(byte) = seq.next_element()? {
+                let Some(slot) = dest.get_mut(i) else {
+                    return Err(A::Error::custom(TryFromSliceError(())));
+                };
+                *slot = byte;
+                i += 1;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use serde_impl::BuilderSeed;
+
+/// An error returned when a [`Cursor`] read attempts to consume more bytes than remain
+/// in the blob.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExhaustedInput(pub(crate) ());
+
+impl fmt::Display for ExhaustedInput {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("attempted to read past the end of the data blob")
+    }
+}
+
+impl core::error::Error for ExhaustedInput {}
+
+/// A streaming reader over a [`Reader`] blob that tracks a read offset.
+///
+/// Every reading method here returns a [`Result`] rather than panicking when the blob
+/// is drained, so a `Cursor` can be handed to untrusted decoders without risking a panic
+/// on malformed input.
+#[derive(Clone, Copy)]
+pub struct Cursor<'a> {
+    reader: Reader<'a>,
+    pos: u32,
+    mark: u32,
+}
+
+impl<'a> From<Reader<'a>> for Cursor<'a> {
+    #[inline]
+    fn from(reader: Reader<'a>) -> Self {
+        Self::new(reader)
+    }
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a new cursor over the given blob, positioned at the start.
+    #[inline]
+    pub const fn new(reader: Reader<'a>) -> Self {
+        Self {
+            reader,
+            pos: 0,
+            mark: 0,
+        }
+    }
+
+    /// The number of bytes that haven't been read yet.
+    #[inline]
+    pub const fn remaining(&self) -> u32 {
+        self.reader.len() - self.pos
+    }
+
+    /// Reads and returns the next byte, advancing the cursor by one.
+    #[inline]
+    pub fn next(&mut self) -> Result<u8, ExhaustedInput> {
+        let byte = *self
+            .reader
+            .as_slice()
+            .get(self.pos as usize)
+            .ok_or(ExhaustedInput(()))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    /// Fills `buf` with the next `buf.len()` bytes, advancing the cursor past them.
+    ///
+    /// If fewer bytes remain than `buf` can hold, no bytes are consumed and an
+    /// [`ExhaustedInput`] error is returned.
+    #[inline]
+    pub fn next_n(&mut self, buf: &mut [u8]) -> Result<(), ExhaustedInput> {
+        let len = u32::try_from(buf.len()).map_err(|_| ExhaustedInput(()))?;
+        if len > self.remaining() {
+            return Err(ExhaustedInput(()));
+        }
+
+        let start = self.pos as usize;
+        buf.copy_from_slice(&self.reader.as_slice()[start..start + buf.len()]);
+        self.pos += len;
+        Ok(())
+    }
+
+    /// Saves the current position so it can later be restored with [`reset`](Self::reset).
+    #[inline]
+    pub fn mark(&mut self) {
+        self.mark = self.pos;
+    }
+
+    /// Restores the position saved by the last call to [`mark`](Self::mark) (or the
+    /// start of the blob, if `mark` was never called).
+    #[inline]
+    pub fn reset(&mut self) {
+        self.pos = self.mark;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> std::io::Read for Cursor<'a> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = core::cmp::min(buf.len(), self.remaining() as usize);
+        self.next_n(&mut buf[..n])
+            .expect("n was clamped to the number of remaining bytes");
+        Ok(n)
+    }
+}
+
+#[cfg(all(feature = "core_io", not(feature = "std")))]
+impl<'a> core_io::Read for Cursor<'a> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> core_io::Result<usize> {
+        let n = core::cmp::min(buf.len(), self.remaining() as usize);
+        self.next_n(&mut buf[..n])
+            .expect("n was clamped to the number of remaining bytes");
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a `Builder` backed by `storage`, for tests that need a mutable blob
+    /// without going through a full message/arena.
+    fn test_builder(storage: &mut [u8]) -> Builder<'_> {
+        // Scoped so the immutable borrow `ptr::Reader::new` needs for validation ends
+        // before we take the mutable pointer below.
+        let len = {
+            let Some(validated) = ptr::Reader::new(&*storage) else {
+                unreachable!("a test fixture's storage always fits in a data blob")
+            };
+            validated.len()
+        };
+        let data = core::ptr::NonNull::new(storage.as_mut_ptr()).unwrap();
+        Builder(unsafe { ptr::Builder::new_unchecked(data, len) })
+    }
+
+    #[test]
+    fn scalar_accessors_dont_overflow_near_u32_max() {
+        let reader = Reader::from_slice(b"0123456789");
+
+        // `offset + width` overflows `usize` on 32-bit targets if computed naively;
+        // this must return `None` rather than panicking or wrapping.
+        assert_eq!(reader.get_u64_le(u32::MAX - 2), None);
+        assert_eq!(reader.get_u16_be(u32::MAX), None);
+    }
+
+    #[test]
+    fn scalar_accessors_round_trip_within_bounds() {
+        let mut storage = [0u8; 8];
+        let mut builder = test_builder(&mut storage);
+
+        builder.set_u24_be(0, 0x01_02_03).unwrap();
+        assert_eq!(builder.get_u24_be(0), Some(0x01_02_03));
+        assert_eq!(&storage[..3], &[0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn ct_eq_matches_partial_eq_semantics() {
+        let reader = Reader::from_slice(b"secret");
+
+        assert!(reader.ct_eq(b"secret"));
+        assert!(!reader.ct_eq(b"secreT"));
+        assert!(!reader.ct_eq(b"secre"));
+        assert!(!reader.ct_eq(b"secretly"));
+    }
+
+    #[test]
+    fn subslice_narrows_without_copying() {
+        let reader = Reader::from_slice(b"hello world");
+
+        let sub = reader.subslice(6..11).unwrap();
+        assert_eq!(sub.as_slice(), b"world");
+        assert_eq!(sub.as_slice().as_ptr(), reader.as_slice()[6..].as_ptr());
+    }
+
+    #[test]
+    fn subslice_rejects_out_of_range_bounds() {
+        let reader = Reader::from_slice(b"hello");
+
+        assert!(reader.subslice(0..6).is_err());
+        assert!(reader.subslice(3..1).is_err());
+    }
+
+    #[test]
+    fn cursor_next_n_rejects_a_request_bigger_than_what_remains() {
+        let mut cursor = Cursor::new(Reader::from_slice(b"hi"));
+        let mut buf = [0u8; 3];
+
+        assert_eq!(cursor.next_n(&mut buf), Err(ExhaustedInput(())));
+        // A failed read shouldn't consume anything.
+        assert_eq!(cursor.remaining(), 2);
+    }
+
+    #[test]
+    fn cursor_next_errors_once_drained() {
+        let mut cursor = Cursor::new(Reader::from_slice(b"a"));
+
+        assert_eq!(cursor.next(), Ok(b'a'));
+        assert_eq!(cursor.next(), Err(ExhaustedInput(())));
+    }
+
+    #[test]
+    fn cursor_reset_restores_the_last_mark() {
+        let mut cursor = Cursor::new(Reader::from_slice(b"abc"));
+
+        assert_eq!(cursor.next(), Ok(b'a'));
+        cursor.mark();
+        assert_eq!(cursor.next(), Ok(b'b'));
+        cursor.reset();
+        assert_eq!(cursor.next(), Ok(b'b'));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_through_a_human_readable_format() {
+        use serde::de::DeserializeSeed;
+
+        // `serialize_bytes` has no native representation in JSON, so `serde_json`
+        // falls back to encoding (and reading back) a sequence of `u8` — exercising
+        // `BuilderVisitor::visit_seq` rather than `visit_bytes`.
+        let reader = Reader::from_slice(b"hello");
+        let json = serde_json::to_string(&reader).unwrap();
+
+        let mut storage = *b"\0\0\0\0\0";
+        let mut builder = test_builder(&mut storage);
+
+        let mut de = serde_json::Deserializer::from_str(&json);
+        BuilderSeed(&mut builder).deserialize(&mut de).unwrap();
+
+        assert_eq!(&storage, b"hello");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_deserializes_a_reader_from_a_borrowing_format() {
+        let bytes = *b"hello";
+        let reader = Reader::from_slice(&bytes);
+        let encoded = bincode::serialize(&reader).unwrap();
+
+        let decoded: Reader<'_> = bincode::deserialize(&encoded).unwrap();
+
+        assert_eq!(decoded.as_slice(), b"hello");
+    }
+}